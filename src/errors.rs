@@ -0,0 +1,64 @@
+use core::fmt;
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+/// Error that can happen when decoding a multihash.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecodeError {
+    /// The multihash uses a code that isn't recognized by this crate.
+    UnknownCode,
+    /// The length of the multihash doesn't match what the code and data say it should be.
+    BadInputLength,
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DecodeError::UnknownCode => write!(f, "Unknown multihash code"),
+            DecodeError::BadInputLength => write!(f, "Invalid multihash input length"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DecodeError {}
+
+/// Error that can happen when decoding a multihash that owns its data.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodeOwnedError {
+    /// The error that occurred.
+    pub error: DecodeError,
+    /// The data that was being parsed.
+    pub data: Vec<u8>,
+}
+
+impl fmt::Display for DecodeOwnedError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.error)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DecodeOwnedError {}
+
+/// Error that can happen when encoding a multihash.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EncodeError {
+    /// The hashing algorithm is not supported by this crate.
+    UnsupportedType,
+    /// The length of a precomputed digest doesn't match what the hashing algorithm expects.
+    BadDigestLength,
+}
+
+impl fmt::Display for EncodeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            EncodeError::UnsupportedType => write!(f, "Unsupported multihash type"),
+            EncodeError::BadDigestLength => write!(f, "Invalid digest input length"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for EncodeError {}
@@ -0,0 +1,137 @@
+use blake2::digest::VariableOutput;
+use sha2::Digest;
+use tiny_keccak::Keccak;
+
+use crate::{Hash, Multihash};
+
+/// Incremental, stateful hasher that produces a [`Multihash`] once finalized.
+///
+/// Unlike [`encode`](crate::encode), a `Hasher` doesn't need the whole input in memory at
+/// once: data can be fed in through repeated calls to [`update`](Hasher::update), for example
+/// while reading a large file or a network stream.
+///
+/// # Examples
+///
+/// ```
+/// use multihash::{Hash, Hasher};
+///
+/// let mut hasher = Hasher::new(Hash::SHA2256);
+/// hasher.update(b"hello ");
+/// hasher.update(b"world");
+/// assert_eq!(hasher.finalize(), multihash::encode(Hash::SHA2256, b"hello world").unwrap());
+/// ```
+pub struct Hasher {
+    hash: Hash,
+    state: State,
+}
+
+enum State {
+    Identity(Vec<u8>),
+    Sha1(sha1::Sha1),
+    Sha2256(sha2::Sha256),
+    Sha2512(sha2::Sha512),
+    Keccak(Keccak),
+    Blake2b(blake2::VarBlake2b),
+    Blake2s(blake2::VarBlake2s),
+    Blake3(Box<blake3::Hasher>),
+}
+
+impl Hasher {
+    /// Creates a new hasher for the given algorithm.
+    pub fn new(hash: Hash) -> Hasher {
+        let state = match hash {
+            Hash::Identity => State::Identity(Vec::new()),
+            Hash::SHA1 => State::Sha1(sha1::Sha1::new()),
+            Hash::SHA2256 => State::Sha2256(sha2::Sha256::default()),
+            Hash::SHA2512 => State::Sha2512(sha2::Sha512::default()),
+            Hash::SHA3224 => State::Keccak(Keccak::new_sha3_224()),
+            Hash::SHA3256 => State::Keccak(Keccak::new_sha3_256()),
+            Hash::SHA3384 => State::Keccak(Keccak::new_sha3_384()),
+            Hash::SHA3512 => State::Keccak(Keccak::new_sha3_512()),
+            Hash::Keccak224 => State::Keccak(Keccak::new_keccak224()),
+            Hash::Keccak256 => State::Keccak(Keccak::new_keccak256()),
+            Hash::Keccak384 => State::Keccak(Keccak::new_keccak384()),
+            Hash::Keccak512 => State::Keccak(Keccak::new_keccak512()),
+            Hash::Blake2b256 => {
+                State::Blake2b(blake2::VarBlake2b::new(32).expect("valid BLAKE2b digest size"))
+            }
+            Hash::Blake2b512 => {
+                State::Blake2b(blake2::VarBlake2b::new(64).expect("valid BLAKE2b digest size"))
+            }
+            Hash::Blake2s128 => {
+                State::Blake2s(blake2::VarBlake2s::new(16).expect("valid BLAKE2s digest size"))
+            }
+            Hash::Blake2s256 => {
+                State::Blake2s(blake2::VarBlake2s::new(32).expect("valid BLAKE2s digest size"))
+            }
+            Hash::Blake3256 => State::Blake3(Box::new(blake3::Hasher::new())),
+        };
+
+        Hasher { hash, state }
+    }
+
+    /// Feeds more data into the hasher.
+    pub fn update(&mut self, input: &[u8]) {
+        match &mut self.state {
+            State::Identity(buf) => buf.extend_from_slice(input),
+            State::Sha1(h) => h.update(input),
+            State::Sha2256(h) => h.input(input),
+            State::Sha2512(h) => h.input(input),
+            State::Keccak(k) => k.update(input),
+            // `VarBlake2b`/`VarBlake2s` only implement `digest::Input`, not `sha2::Digest`, but
+            // both traits name the method `input` — qualify it to avoid pulling in a second
+            // `Input`-like trait that would make `.input()` on the sha2 arms above ambiguous.
+            State::Blake2b(h) => blake2::digest::Input::input(h, input),
+            State::Blake2s(h) => blake2::digest::Input::input(h, input),
+            State::Blake3(h) => {
+                h.update(input);
+            }
+        }
+    }
+
+    /// Consumes the hasher and produces the resulting `Multihash`.
+    pub fn finalize(self) -> Multihash {
+        let code = self.hash.code();
+        let size = self.hash.size();
+
+        match self.state {
+            State::Identity(buf) => {
+                let mut output = Vec::new();
+                crate::varint::encode(code, &mut output);
+                crate::varint::encode(buf.len() as u64, &mut output);
+                output.extend_from_slice(&buf);
+                Multihash { bytes: output }
+            }
+            State::Sha1(h) => {
+                let digest = h.digest().bytes();
+                Self::finish(code, size, &digest)
+            }
+            State::Sha2256(h) => Self::finish(code, size, h.result().as_ref()),
+            State::Sha2512(h) => Self::finish(code, size, h.result().as_ref()),
+            State::Keccak(k) => {
+                let mut digest = vec![0u8; size as usize];
+                k.finalize(&mut digest);
+                Self::finish(code, size, &digest)
+            }
+            State::Blake2b(h) => {
+                let mut digest = vec![0u8; size as usize];
+                h.variable_result(|res| digest.copy_from_slice(res));
+                Self::finish(code, size, &digest)
+            }
+            State::Blake2s(h) => {
+                let mut digest = vec![0u8; size as usize];
+                h.variable_result(|res| digest.copy_from_slice(res));
+                Self::finish(code, size, &digest)
+            }
+            State::Blake3(h) => Self::finish(code, size, h.finalize().as_bytes()),
+        }
+    }
+
+    fn finish(code: u64, size: u8, digest: &[u8]) -> Multihash {
+        let mut output = Vec::new();
+        crate::varint::encode(code, &mut output);
+        crate::varint::encode(u64::from(size), &mut output);
+        output.extend_from_slice(digest);
+        Multihash { bytes: output }
+    }
+}
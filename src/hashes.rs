@@ -0,0 +1,120 @@
+/// The hashing algorithms supported by this crate.
+///
+/// The codes match the ones in the
+/// [multicodec table](https://github.com/multiformats/multicodec/blob/master/table.csv).
+#[derive(PartialEq, Eq, Clone, Debug, Copy, Hash)]
+#[allow(non_camel_case_types)]
+pub enum Hash {
+    /// Identity hash (copies the input as-is into the digest, up to any length).
+    Identity,
+    /// SHA-1 (20-byte digest).
+    SHA1,
+    /// SHA-256 (32-byte digest).
+    SHA2256,
+    /// SHA-512 (64-byte digest).
+    SHA2512,
+    /// SHA3-224 (28-byte digest).
+    SHA3224,
+    /// SHA3-256 (32-byte digest).
+    SHA3256,
+    /// SHA3-384 (48-byte digest).
+    SHA3384,
+    /// SHA3-512 (64-byte digest).
+    SHA3512,
+    /// Keccak-224 (28-byte digest).
+    Keccak224,
+    /// Keccak-256 (32-byte digest).
+    Keccak256,
+    /// Keccak-384 (48-byte digest).
+    Keccak384,
+    /// Keccak-512 (64-byte digest).
+    Keccak512,
+    /// BLAKE2b-256 (32-byte digest).
+    Blake2b256,
+    /// BLAKE2b-512 (64-byte digest).
+    Blake2b512,
+    /// BLAKE2s-128 (16-byte digest).
+    Blake2s128,
+    /// BLAKE2s-256 (32-byte digest).
+    Blake2s256,
+    /// BLAKE3-256 (32-byte digest).
+    Blake3256,
+}
+
+impl Hash {
+    /// Returns the numerical code of this hashing algorithm.
+    ///
+    /// Codes for some algorithms (e.g. the BLAKE2 family) don't fit in a single byte, hence
+    /// the `u64` return type.
+    pub fn code(&self) -> u64 {
+        match *self {
+            Hash::Identity => 0x00,
+            Hash::SHA1 => 0x11,
+            Hash::SHA2256 => 0x12,
+            Hash::SHA2512 => 0x13,
+            Hash::SHA3512 => 0x14,
+            Hash::SHA3384 => 0x15,
+            Hash::SHA3256 => 0x16,
+            Hash::SHA3224 => 0x17,
+            Hash::Keccak224 => 0x1a,
+            Hash::Keccak256 => 0x1b,
+            Hash::Keccak384 => 0x1c,
+            Hash::Keccak512 => 0x1d,
+            Hash::Blake3256 => 0x1e,
+            Hash::Blake2b256 => 0xb220,
+            Hash::Blake2b512 => 0xb240,
+            Hash::Blake2s128 => 0xb250,
+            Hash::Blake2s256 => 0xb260,
+        }
+    }
+
+    /// Returns the size in bytes of the digest produced by this hashing algorithm.
+    ///
+    /// For [`Hash::Identity`], the digest has no fixed size: this returns `0`, and the actual
+    /// size is whatever the input/digest happens to be.
+    pub fn size(&self) -> u8 {
+        match *self {
+            Hash::Identity => 0,
+            Hash::SHA1 => 20,
+            Hash::SHA2256 => 32,
+            Hash::SHA2512 => 64,
+            Hash::SHA3224 => 28,
+            Hash::SHA3256 => 32,
+            Hash::SHA3384 => 48,
+            Hash::SHA3512 => 64,
+            Hash::Keccak224 => 28,
+            Hash::Keccak256 => 32,
+            Hash::Keccak384 => 48,
+            Hash::Keccak512 => 64,
+            Hash::Blake2b256 => 32,
+            Hash::Blake2b512 => 64,
+            Hash::Blake2s128 => 16,
+            Hash::Blake2s256 => 32,
+            Hash::Blake3256 => 32,
+        }
+    }
+
+    /// Returns the hashing algorithm corresponding to a code, or `None` if the code is unknown.
+    pub fn from_code(code: u64) -> Option<Hash> {
+        Some(match code {
+            0x00 => Hash::Identity,
+            0x11 => Hash::SHA1,
+            0x12 => Hash::SHA2256,
+            0x13 => Hash::SHA2512,
+            0x14 => Hash::SHA3512,
+            0x15 => Hash::SHA3384,
+            0x16 => Hash::SHA3256,
+            0x17 => Hash::SHA3224,
+            0x1a => Hash::Keccak224,
+            0x1b => Hash::Keccak256,
+            0x1c => Hash::Keccak384,
+            0x1d => Hash::Keccak512,
+            0x1e => Hash::Blake3256,
+            0xb220 => Hash::Blake2b256,
+            0xb240 => Hash::Blake2b512,
+            0xb250 => Hash::Blake2s128,
+            0xb260 => Hash::Blake2s256,
+            _ => return None,
+        })
+    }
+}
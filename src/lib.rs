@@ -5,17 +5,42 @@
 //! A `Multihash` is a structure that contains a hashing algorithm, plus some hashed data.
 //! A `MultihashRef` is the same as a `Multihash`, except that it doesn't own its data.
 //!
+//! # Features
+//!
+//! The `std` feature is enabled by default. Disabling it (`default-features = false`) builds
+//! this crate as `no_std` against `alloc`, which is all the core `Multihash`/`MultihashRef`
+//! types and the decoding logic need. The hashing backends behind [`encode`] and [`Hasher`]
+//! are only available with `std` enabled.
+//!
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+
+#[cfg(not(feature = "std"))]
+use alloc::{borrow::ToOwned, vec::Vec};
 
 mod errors;
+#[cfg(feature = "std")]
+mod hasher;
 mod hashes;
+#[cfg(feature = "serde")]
+mod serde_impl;
+mod varint;
 
+#[cfg(feature = "std")]
 use sha2::Digest;
+#[cfg(feature = "std")]
 use tiny_keccak::Keccak;
 
 pub use errors::{DecodeError, DecodeOwnedError, EncodeError};
+#[cfg(feature = "std")]
+pub use hasher::Hasher;
 pub use hashes::Hash;
 
 // Helper macro for encoding input into output using sha1, sha2 or tiny_keccak
+#[cfg(feature = "std")]
 macro_rules! encode {
     (sha1, Sha1, $input:expr, $output:expr) => {{
         let mut hasher = sha1::Sha1::new();
@@ -32,9 +57,25 @@ macro_rules! encode {
         kec.update($input);
         kec.finalize($output);
     }};
+    (blake2b, $size:expr, $input:expr, $output:expr) => {{
+        use blake2::digest::{Input, VariableOutput};
+        let mut hasher = blake2::VarBlake2b::new($size).expect("valid BLAKE2b digest size");
+        hasher.input($input);
+        hasher.variable_result(|res| $output.copy_from_slice(res));
+    }};
+    (blake2s, $size:expr, $input:expr, $output:expr) => {{
+        use blake2::digest::{Input, VariableOutput};
+        let mut hasher = blake2::VarBlake2s::new($size).expect("valid BLAKE2s digest size");
+        hasher.input($input);
+        hasher.variable_result(|res| $output.copy_from_slice(res));
+    }};
+    (blake3, $input:expr, $output:expr) => {{
+        $output.copy_from_slice(blake3::hash($input).as_bytes());
+    }};
 }
 
 // And another one to keep the matching DRY
+#[cfg(feature = "std")]
 macro_rules! match_encoder {
     ($hash:ident for ($input:expr, $output:expr) {
         $( $hashtype:ident => $lib:ident :: $method:ident, )*
@@ -68,26 +109,44 @@ macro_rules! match_encoder {
 /// );
 /// ```
 ///
+#[cfg(feature = "std")]
 pub fn encode(hash: Hash, input: &[u8]) -> Result<Multihash, EncodeError> {
+    // The identity hash has no digest of its own: the input is copied verbatim.
+    if let Hash::Identity = hash {
+        let mut output = Vec::new();
+        varint::encode(hash.code(), &mut output);
+        varint::encode(input.len() as u64, &mut output);
+        output.extend_from_slice(input);
+        return Ok(Multihash { bytes: output });
+    }
+
     let size = hash.size();
     let mut output = Vec::new();
-    output.resize(2 + size as usize, 0);
-    output[0] = hash.code();
-    output[1] = size;
-
-    match_encoder!(hash for (input, &mut output[2..]) {
-        SHA1 => sha1::Sha1,
-        SHA2256 => sha2::Sha256,
-        SHA2512 => sha2::Sha512,
-        SHA3224 => tiny::new_sha3_224,
-        SHA3256 => tiny::new_sha3_256,
-        SHA3384 => tiny::new_sha3_384,
-        SHA3512 => tiny::new_sha3_512,
-        Keccak224 => tiny::new_keccak224,
-        Keccak256 => tiny::new_keccak256,
-        Keccak384 => tiny::new_keccak384,
-        Keccak512 => tiny::new_keccak512,
-    });
+    varint::encode(hash.code(), &mut output);
+    varint::encode(u64::from(size), &mut output);
+    let prefix_len = output.len();
+    output.resize(prefix_len + size as usize, 0);
+
+    match hash {
+        Hash::Blake2b256 => encode!(blake2b, 32, input, &mut output[prefix_len..]),
+        Hash::Blake2b512 => encode!(blake2b, 64, input, &mut output[prefix_len..]),
+        Hash::Blake2s128 => encode!(blake2s, 16, input, &mut output[prefix_len..]),
+        Hash::Blake2s256 => encode!(blake2s, 32, input, &mut output[prefix_len..]),
+        Hash::Blake3256 => encode!(blake3, input, &mut output[prefix_len..]),
+        _ => match_encoder!(hash for (input, &mut output[prefix_len..]) {
+            SHA1 => sha1::Sha1,
+            SHA2256 => sha2::Sha256,
+            SHA2512 => sha2::Sha512,
+            SHA3224 => tiny::new_sha3_224,
+            SHA3256 => tiny::new_sha3_256,
+            SHA3384 => tiny::new_sha3_384,
+            SHA3512 => tiny::new_sha3_512,
+            Keccak224 => tiny::new_keccak224,
+            Keccak256 => tiny::new_keccak256,
+            Keccak384 => tiny::new_keccak384,
+            Keccak512 => tiny::new_keccak512,
+        }),
+    }
 
     Ok(Multihash { bytes: output })
 }
@@ -99,6 +158,28 @@ pub struct Multihash {
 }
 
 impl Multihash {
+    /// Builds a `Multihash` by prepending the code and length prefix for `hash` around an
+    /// already-computed `digest`, without hashing anything.
+    ///
+    /// This is useful when the digest was produced externally, e.g. by hardware-accelerated
+    /// hashing, a different crate, or the streaming [`Hasher`] API.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `digest`'s length doesn't match what `hash` expects. Variable-length
+    /// algorithms, such as [`Hash::Identity`], accept a digest of any length.
+    pub fn wrap(hash: Hash, digest: &[u8]) -> Result<Multihash, EncodeError> {
+        if hash != Hash::Identity && digest.len() != hash.size() as usize {
+            return Err(EncodeError::BadDigestLength);
+        }
+
+        let mut output = Vec::new();
+        varint::encode(hash.code(), &mut output);
+        varint::encode(digest.len() as u64, &mut output);
+        output.extend_from_slice(digest);
+        Ok(Multihash { bytes: output })
+    }
+
     /// Verifies whether `bytes` contains a valid multihash, and if so returns a `Multihash`.
     #[inline]
     pub fn from_bytes(bytes: Vec<u8>) -> Result<Multihash, DecodeOwnedError> {
@@ -126,8 +207,15 @@ impl Multihash {
 
     /// Builds a `MultihashRef` corresponding to this `Multihash`.
     #[inline]
-    pub fn as_ref(&self) -> MultihashRef {
-        MultihashRef { bytes: &self.bytes }
+    pub fn as_ref(&self) -> MultihashRef<'_> {
+        // The bytes are known to be a valid multihash already, since that's the invariant
+        // upheld by `Multihash`, so parsing the varint prefix here can't fail.
+        let (_, rest) = varint::decode(&self.bytes).expect("multihash is known to be valid");
+        let (_, rest) = varint::decode(rest).expect("multihash is known to be valid");
+        MultihashRef {
+            bytes: &self.bytes,
+            digest_start: self.bytes.len() - rest.len(),
+        }
     }
 
     /// Returns which hashing algorithm is used in this multihash.
@@ -154,6 +242,9 @@ impl<'a> PartialEq<MultihashRef<'a>> for Multihash {
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub struct MultihashRef<'a> {
     bytes: &'a [u8],
+    // Offset of the digest within `bytes`, i.e. the combined length of the code and
+    // digest-length varints.
+    digest_start: usize,
 }
 
 impl<'a> MultihashRef<'a> {
@@ -163,41 +254,40 @@ impl<'a> MultihashRef<'a> {
             return Err(DecodeError::BadInputLength);
         }
 
-        // TODO: note that `input[0]` and `input[1]` and technically variable-length integers,
-        // but there's no hashing algorithm implemented in this crate whose code or digest length
-        // is superior to 128
-        let code = input[0];
-
-        // TODO: see comment just above about varints
-        if input[0] >= 128 || input[1] >= 128 {
-            return Err(DecodeError::BadInputLength);
-        }
-
+        let (code, rest) = varint::decode(input)?;
         let alg = Hash::from_code(code).ok_or(DecodeError::UnknownCode)?;
-        let hash_len = alg.size() as usize;
 
-        // length of input should be exactly hash_len + 2
-        if input.len() != hash_len + 2 {
+        let (hash_len, rest) = varint::decode(rest)?;
+        let hash_len = hash_len as usize;
+
+        // The identity hash has no fixed digest length: it matches whatever was encoded.
+        if alg != Hash::Identity && hash_len != alg.size() as usize {
             return Err(DecodeError::BadInputLength);
         }
 
-        if input[1] as usize != hash_len {
+        // what's left of the input after both varints should be exactly the digest
+        if rest.len() != hash_len {
             return Err(DecodeError::BadInputLength);
         }
 
-        Ok(MultihashRef { bytes: input })
+        let digest_start = input.len() - rest.len();
+        Ok(MultihashRef {
+            bytes: input,
+            digest_start,
+        })
     }
 
     /// Returns which hashing algorithm is used in this multihash.
     #[inline]
     pub fn algorithm(&self) -> Hash {
-        Hash::from_code(self.bytes[0]).expect("multihash is known to be valid")
+        let (code, _) = varint::decode(self.bytes).expect("multihash is known to be valid");
+        Hash::from_code(code).expect("multihash is known to be valid")
     }
 
     /// Returns the hashed data.
     #[inline]
     pub fn digest(&self) -> &'a [u8] {
-        &self.bytes[2..]
+        &self.bytes[self.digest_start..]
     }
 
     /// Builds a `Multihash` that owns the data.
@@ -213,7 +303,7 @@ impl<'a> MultihashRef<'a> {
     /// Returns the bytes representation of this multihash.
     #[inline]
     pub fn as_bytes(&self) -> &'a [u8] {
-        &self.bytes
+        self.bytes
     }
 }
 
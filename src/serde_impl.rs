@@ -0,0 +1,69 @@
+//! `Serialize`/`Deserialize` impls for `Multihash`, behind the `serde` feature.
+
+use core::fmt;
+
+#[cfg(not(feature = "std"))]
+use alloc::{borrow::ToOwned, vec::Vec};
+
+use serde::de::{Deserialize, Deserializer, Error as DeError, SeqAccess, Visitor};
+use serde::ser::{Serialize, Serializer};
+
+use crate::Multihash;
+
+impl Serialize for Multihash {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_bytes(self.as_bytes())
+    }
+}
+
+// `serialize_bytes` above is read back differently depending on the format: binary formats
+// that have a distinct byte-string type (dag-cbor, MessagePack, ...) call `visit_bytes`/
+// `visit_byte_buf`, while formats with no such type (JSON, ...) fall back to encoding as a
+// sequence and call `visit_seq`. Handling both keeps the round-trip working everywhere.
+struct BytesVisitor;
+
+impl<'de> Visitor<'de> for BytesVisitor {
+    type Value = Vec<u8>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a byte array or a sequence of bytes")
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: DeError,
+    {
+        Ok(v.to_owned())
+    }
+
+    fn visit_byte_buf<E>(self, v: Vec<u8>) -> Result<Self::Value, E>
+    where
+        E: DeError,
+    {
+        Ok(v)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+        while let Some(byte) = seq.next_element()? {
+            bytes.push(byte);
+        }
+        Ok(bytes)
+    }
+}
+
+impl<'de> Deserialize<'de> for Multihash {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let bytes = deserializer.deserialize_bytes(BytesVisitor)?;
+        Multihash::from_bytes(bytes).map_err(|err| D::Error::custom(err.error))
+    }
+}
@@ -0,0 +1,43 @@
+//! Minimal unsigned LEB128 varint support, used to encode the hash code and digest length
+//! in the multihash wire format.
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use crate::errors::DecodeError;
+
+/// 9 bytes of 7 payload bits each cover values up to `2^63 - 1`, matching the multiformats
+/// unsigned-varint spec. `encode` never needs more than that for the code/length values used by
+/// this crate, and `decode` rejects anything longer as overlong/corrupt.
+const MAX_VARINT_BYTES: usize = 9;
+
+/// Appends the unsigned varint encoding of `value` to `out`.
+pub fn encode(mut value: u64, out: &mut Vec<u8>) {
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value == 0 {
+            out.push(byte);
+            return;
+        }
+        out.push(byte | 0x80);
+    }
+}
+
+/// Decodes an unsigned varint from the start of `input`.
+///
+/// Returns the decoded value along with the remainder of `input` after the varint. Rejects
+/// encodings that run past `MAX_VARINT_BYTES` bytes.
+pub fn decode(input: &[u8]) -> Result<(u64, &[u8]), DecodeError> {
+    let mut value: u64 = 0;
+
+    for i in 0..MAX_VARINT_BYTES {
+        let byte = *input.get(i).ok_or(DecodeError::BadInputLength)?;
+        value |= u64::from(byte & 0x7f) << (7 * i);
+        if byte & 0x80 == 0 {
+            return Ok((value, &input[i + 1..]));
+        }
+    }
+
+    Err(DecodeError::BadInputLength)
+}